@@ -0,0 +1,111 @@
+use crate::Matrix;
+
+impl Matrix {
+    /// Parse a dense matrix from a whitespace-and-newline grid
+    ///
+    /// Each line becomes a row and the values on a line are separated by any
+    /// whitespace. Blank lines are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let matrix = lingebra::Matrix::from_str_dense("1 2 3\n4 5 6");
+    /// let expected = lingebra::Matrix::new(vec![vec![1.0, 2.0, 3.0],
+    ///                                           vec![4.0, 5.0, 6.0]]);
+    /// assert_eq!(matrix, expected);
+    /// ```
+    pub fn from_str_dense(input: &str) -> Matrix {
+        let matrix: Vec<Vec<f64>> = input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|value| value.parse().expect("Invalid number in matrix!"))
+                    .collect()
+            })
+            .collect();
+        Matrix::new(matrix)
+    }
+
+    /// Serialize the matrix into the whitespace-and-newline grid format
+    ///
+    /// The result round-trips through [`Matrix::from_str_dense`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let matrix = lingebra::Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// assert_eq!(matrix.to_string_dense(), "1 2\n3 4");
+    /// assert_eq!(lingebra::Matrix::from_str_dense(&matrix.to_string_dense()), matrix);
+    /// ```
+    pub fn to_string_dense(&self) -> String {
+        let (height, width) = self.dim();
+        let mut lines = Vec::with_capacity(height);
+        for i in 0..height {
+            let row: Vec<String> = (0..width).map(|j| self[i][j].to_string()).collect();
+            lines.push(row.join(" "));
+        }
+        lines.join("\n")
+    }
+
+    /// Read a matrix from the Matrix Market coordinate format
+    ///
+    /// <https://math.nist.gov/MatrixMarket/formats.html>
+    ///
+    /// Expects a `%%MatrixMarket matrix coordinate real general` banner,
+    /// skips `%` comment lines, reads the `rows cols nnz` size line and then
+    /// `nnz` `row col value` entries with 1-based indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let input = "%%MatrixMarket matrix coordinate real general\n\
+    ///              % a small example\n\
+    ///              2 2 2\n\
+    ///              1 1 4.0\n\
+    ///              2 2 5.0\n";
+    /// let matrix = lingebra::Matrix::from_matrix_market(input);
+    /// let expected = lingebra::Matrix::new(vec![vec![4.0, 0.0], vec![0.0, 5.0]]);
+    /// assert_eq!(matrix, expected);
+    /// ```
+    pub fn from_matrix_market(input: &str) -> Matrix {
+        let mut lines = input.lines();
+
+        let banner = lines.next().expect("Missing Matrix Market banner!");
+        assert!(
+            banner.starts_with("%%MatrixMarket"),
+            "Not a Matrix Market file!"
+        );
+
+        let mut lines = lines.filter(|line| !line.starts_with('%') && !line.trim().is_empty());
+
+        let size = lines.next().expect("Missing size line!");
+        let mut dimensions = size.split_whitespace();
+        let rows: usize = dimensions
+            .next()
+            .expect("Missing row count!")
+            .parse()
+            .expect("Invalid row count!");
+        let cols: usize = dimensions
+            .next()
+            .expect("Missing column count!")
+            .parse()
+            .expect("Invalid column count!");
+        let nnz: usize = dimensions
+            .next()
+            .expect("Missing non-zero count!")
+            .parse()
+            .expect("Invalid non-zero count!");
+
+        let mut matrix = vec![vec![0.0; cols]; rows];
+        for _ in 0..nnz {
+            let entry = lines.next().expect("Fewer entries than declared!");
+            let mut fields = entry.split_whitespace();
+            let row: usize = fields.next().unwrap().parse().expect("Invalid row index!");
+            let col: usize = fields.next().unwrap().parse().expect("Invalid column index!");
+            let value: f64 = fields.next().unwrap().parse().expect("Invalid value!");
+            matrix[row - 1][col - 1] = value;
+        }
+        Matrix::new(matrix)
+    }
+}
@@ -2,9 +2,13 @@
 //!
 //! `lingebra` is a library that provides simple implementation of objects and operations
 //! that are used in linear algebra.
+mod io;
+mod lu;
 mod matrix;
+mod sparse;
 
 pub use matrix::Matrix;
+pub use sparse::SparseMatrix;
 use std::f64::consts::E;
 use std::f64::consts::PI;
 
@@ -77,7 +81,25 @@ pub fn orthogonal(a: &[f64], b: &[f64]) -> bool {
     vector_dot_product(a, b) == 0.0
 }
 
-/// Check if two vectors are orthogonal to each other
+/// Check if two vectors are orthogonal within a given tolerance
+///
+/// Computed floats are almost never exactly orthogonal, so the dot product is
+/// compared against `epsilon` instead of zero.
+///
+/// # Examples
+///
+/// ```
+/// let vector_a = vec![1.0, 0.0];
+/// let vector_b = vec![0.0, 1.0];
+/// assert!(lingebra::approx_orthogonal(&vector_a, &vector_b, 1e-9));
+/// let vector_c = vec![1.0, 1.0];
+/// assert!(!lingebra::approx_orthogonal(&vector_a, &vector_c, 1e-9));
+/// ```
+pub fn approx_orthogonal(a: &[f64], b: &[f64], epsilon: f64) -> bool {
+    vector_dot_product(a, b).abs() <= epsilon
+}
+
+/// Check if a collection of vectors are all orthogonal to each other
 ///
 /// # Examples
 ///
@@ -86,22 +108,143 @@ pub fn orthogonal(a: &[f64], b: &[f64]) -> bool {
 /// let b = vec![0.0, 2.0, -1.0, 0.0];
 /// let c = vec![0.0, 1.0, 2.0, 0.0];
 /// let d = vec![0.0, 0.0, 0.0, 3.0];
-/// assert!(lingebra::all_orthogonal(&vec![&a, &b, &c, &d]));
+/// assert!(lingebra::all_orthogonal(&vec![&a, &b, &c, &d], 1e-9));
 /// ```
-pub fn all_orthogonal(vectors: &[&Vec<f64>]) -> bool {
+pub fn all_orthogonal(vectors: &[&Vec<f64>], epsilon: f64) -> bool {
     if vectors.len() <= 1 {
         true
     } else {
         let test_vector = vectors[0];
-        vectors.iter().skip(1).all(|x| orthogonal(test_vector, x))
-            && all_orthogonal(&vectors[1..].to_vec())
+        vectors
+            .iter()
+            .skip(1)
+            .all(|x| approx_orthogonal(test_vector, x, epsilon))
+            && all_orthogonal(&vectors[1..].to_vec(), epsilon)
     }
 }
 
-pub fn change_base(v: &[f64], base: &[&Vec<f64>]) -> Vec<f64> {
+pub fn change_base(v: &[f64], base: &[&Vec<f64>], epsilon: f64) -> Vec<f64> {
     assert!(
-        all_orthogonal(base),
+        all_orthogonal(base, epsilon),
         "The vectors in base are not all orthogonal to each other"
     );
     base.iter().map(|x| scalar_projection(v, x)).collect()
 }
+
+/// Construct an orthogonal basis from arbitrary vectors using the Gram-Schmidt
+/// process
+///
+/// <https://en.wikipedia.org/wiki/Gram%E2%80%93Schmidt_process>
+///
+/// Starting from `u_0 = v_0`, every subsequent vector has its projection onto
+/// each previously produced basis vector subtracted. Vectors that turn out to
+/// be linearly dependent (their size falls below `1e-9`) are skipped.
+///
+/// # Examples
+///
+/// ```
+/// let v0 = vec![1.0, 1.0, 0.0];
+/// let v1 = vec![1.0, 0.0, 0.0];
+/// let basis = lingebra::gram_schmidt(&[v0, v1]);
+/// assert_eq!(basis.len(), 2);
+/// assert!(lingebra::approx_orthogonal(&basis[0], &basis[1], 1e-9));
+/// ```
+pub fn gram_schmidt(vectors: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let epsilon = 1e-9;
+    let mut basis: Vec<Vec<f64>> = Vec::new();
+    for v in vectors {
+        let mut u = v.clone();
+        for b in &basis {
+            let projection = vector_projection(v, b);
+            u = u
+                .iter()
+                .zip(projection.iter())
+                .map(|(x, p)| x - p)
+                .collect();
+        }
+        if vector_size(&u) >= epsilon {
+            basis.push(u);
+        }
+    }
+    basis
+}
+
+/// Index of the largest value in a slice
+///
+/// Panics on an empty slice.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(lingebra::vector_imax(&[1.0, 4.0, -2.0]), 1);
+/// ```
+pub fn vector_imax(vector: &[f64]) -> usize {
+    assert!(!vector.is_empty(), "Cannot query an empty vector!");
+    let mut index = 0;
+    for (i, value) in vector.iter().enumerate() {
+        if value > &vector[index] {
+            index = i;
+        }
+    }
+    index
+}
+
+/// Index of the smallest value in a slice
+///
+/// Panics on an empty slice.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(lingebra::vector_imin(&[1.0, 4.0, -2.0]), 2);
+/// ```
+pub fn vector_imin(vector: &[f64]) -> usize {
+    assert!(!vector.is_empty(), "Cannot query an empty vector!");
+    let mut index = 0;
+    for (i, value) in vector.iter().enumerate() {
+        if value < &vector[index] {
+            index = i;
+        }
+    }
+    index
+}
+
+/// Index of the largest-absolute value in a slice
+///
+/// Panics on an empty slice.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(lingebra::vector_iamax(&[1.0, 4.0, -7.0]), 2);
+/// ```
+pub fn vector_iamax(vector: &[f64]) -> usize {
+    assert!(!vector.is_empty(), "Cannot query an empty vector!");
+    let mut index = 0;
+    for (i, value) in vector.iter().enumerate() {
+        if value.abs() > vector[index].abs() {
+            index = i;
+        }
+    }
+    index
+}
+
+/// Index of the smallest-absolute value in a slice
+///
+/// Panics on an empty slice.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(lingebra::vector_iamin(&[4.0, -2.0, 7.0]), 1);
+/// ```
+pub fn vector_iamin(vector: &[f64]) -> usize {
+    assert!(!vector.is_empty(), "Cannot query an empty vector!");
+    let mut index = 0;
+    for (i, value) in vector.iter().enumerate() {
+        if value.abs() < vector[index].abs() {
+            index = i;
+        }
+    }
+    index
+}
@@ -0,0 +1,198 @@
+use crate::Matrix;
+
+/// Pivots smaller than this in absolute value are treated as zero, marking the
+/// matrix as singular.
+const EPSILON: f64 = 1e-12;
+
+impl Matrix {
+    /// Compute the LU decomposition with partial pivoting
+    ///
+    /// <https://en.wikipedia.org/wiki/LU_decomposition>
+    ///
+    /// Returns the unit-lower-triangular `L`, the upper-triangular `U` and the
+    /// row permutation applied during pivoting (so that `P * self == L * U`),
+    /// or `None` when the matrix is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let matrix = lingebra::Matrix::new(vec![vec![4.0, 3.0],
+    ///                                         vec![6.0, 3.0]]);
+    /// let (l, u, perm) = matrix.lu().unwrap();
+    /// assert_eq!(perm, vec![1, 0]);
+    /// // L * U reconstructs the row-permuted original matrix.
+    /// let reconstructed = &l * &u;
+    /// let permuted = lingebra::Matrix::new(vec![matrix.row(perm[0]), matrix.row(perm[1])]);
+    /// assert_eq!(reconstructed, permuted);
+    /// ```
+    pub fn lu(&self) -> Option<(Matrix, Matrix, Vec<usize>)> {
+        let (height, width) = self.dim();
+        assert_eq!(height, width, "LU decomposition works only for square matrices!");
+        let n = height;
+
+        let mut a: Vec<Vec<f64>> = (0..n).map(|i| self.row(i)).collect();
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        for k in 0..n {
+            let mut pivot = k;
+            for p in (k + 1)..n {
+                if a[p][k].abs() > a[pivot][k].abs() {
+                    pivot = p;
+                }
+            }
+            if a[pivot][k].abs() < EPSILON {
+                return None;
+            }
+            if pivot != k {
+                a.swap(k, pivot);
+                perm.swap(k, pivot);
+            }
+            for i in (k + 1)..n {
+                let m = a[i][k] / a[k][k];
+                a[i][k] = m;
+                for j in (k + 1)..n {
+                    a[i][j] -= m * a[k][j];
+                }
+            }
+        }
+
+        let mut l = vec![vec![0.0; n]; n];
+        let mut u = vec![vec![0.0; n]; n];
+        for i in 0..n {
+            l[i][i] = 1.0;
+            for j in 0..i {
+                l[i][j] = a[i][j];
+            }
+            for j in i..n {
+                u[i][j] = a[i][j];
+            }
+        }
+
+        Some((Matrix::new(l), Matrix::new(u), perm))
+    }
+
+    /// Determinant of a square matrix computed from its LU decomposition
+    ///
+    /// <https://en.wikipedia.org/wiki/Determinant>
+    ///
+    /// Returns `None` for a singular matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let matrix = lingebra::Matrix::new(vec![vec![4.0, 3.0],
+    ///                                         vec![6.0, 3.0]]);
+    /// assert!((matrix.determinant().unwrap() - (-6.0)).abs() < 1e-9);
+    /// ```
+    pub fn determinant(&self) -> Option<f64> {
+        let (_, u, perm) = self.lu()?;
+        let n = perm.len();
+        let mut det = permutation_sign(&perm);
+        for i in 0..n {
+            det *= u[i][i];
+        }
+        Some(det)
+    }
+
+    /// Solve the linear system `self * x = b`
+    ///
+    /// <https://en.wikipedia.org/wiki/Triangular_matrix#Forward_and_back_substitution>
+    ///
+    /// Returns `None` when the matrix is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let matrix = lingebra::Matrix::new(vec![vec![3.0, 2.0],
+    ///                                         vec![1.0, 2.0]]);
+    /// let x = matrix.solve(&vec![7.0, 5.0]).unwrap();
+    /// assert!((x[0] - 1.0).abs() < 1e-9);
+    /// assert!((x[1] - 2.0).abs() < 1e-9);
+    /// ```
+    pub fn solve(&self, b: &Vec<f64>) -> Option<Vec<f64>> {
+        let (l, u, perm) = self.lu()?;
+        let n = perm.len();
+        assert_eq!(n, b.len(), "Size of matrix does not match with length of the vector!");
+
+        // Forward substitution on L, applying the row permutation to b.
+        let mut y = vec![0.0; n];
+        for i in 0..n {
+            let mut sum = b[perm[i]];
+            for j in 0..i {
+                sum -= l[i][j] * y[j];
+            }
+            y[i] = sum;
+        }
+
+        // Back substitution on U.
+        let mut x = vec![0.0; n];
+        for i in (0..n).rev() {
+            let mut sum = y[i];
+            for j in (i + 1)..n {
+                sum -= u[i][j] * x[j];
+            }
+            x[i] = sum / u[i][i];
+        }
+        Some(x)
+    }
+
+    /// Invert a square matrix by solving against each column of the identity
+    ///
+    /// <https://en.wikipedia.org/wiki/Invertible_matrix>
+    ///
+    /// Returns `None` when the matrix is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let matrix = lingebra::Matrix::new(vec![vec![4.0, 7.0],
+    ///                                         vec![2.0, 6.0]]);
+    /// let inverse = matrix.inverse().unwrap();
+    /// let product = &matrix * &inverse;
+    /// let identity = lingebra::Matrix::identity(2);
+    /// for i in 0..2 {
+    ///     for j in 0..2 {
+    ///         assert!((product[i][j] - identity[i][j]).abs() < 1e-9);
+    ///     }
+    /// }
+    /// ```
+    pub fn inverse(&self) -> Option<Matrix> {
+        let (height, width) = self.dim();
+        assert_eq!(height, width, "Only square matrices can be inverted!");
+        let n = height;
+
+        let mut columns = Vec::with_capacity(n);
+        for i in 0..n {
+            let mut e = vec![0.0; n];
+            e[i] = 1.0;
+            columns.push(self.solve(&e)?);
+        }
+
+        // `columns[i]` is the i-th column of the inverse; reassemble rows.
+        let mut result = vec![vec![0.0; n]; n];
+        for i in 0..n {
+            for j in 0..n {
+                result[i][j] = columns[j][i];
+            }
+        }
+        Some(Matrix::new(result))
+    }
+}
+
+/// Sign of the permutation, i.e. `(-1)^(number of swaps)` needed to sort it.
+fn permutation_sign(perm: &[usize]) -> f64 {
+    let mut perm = perm.to_vec();
+    let mut swaps = 0;
+    for i in 0..perm.len() {
+        while perm[i] != i {
+            let target = perm[i];
+            perm.swap(i, target);
+            swaps += 1;
+        }
+    }
+    if swaps % 2 == 0 {
+        1.0
+    } else {
+        -1.0
+    }
+}
@@ -1,10 +1,14 @@
 use std::cmp::PartialEq;
 use std::fmt;
 use std::ops::Add;
+use std::ops::AddAssign;
 use std::ops::Div;
+use std::ops::DivAssign;
 use std::ops::Index;
 use std::ops::Mul;
+use std::ops::MulAssign;
 use std::ops::Sub;
+use std::ops::SubAssign;
 use std::rc::Rc;
 
 /// Representation of 2-D matrix
@@ -235,7 +239,14 @@ impl Matrix {
         self.matrix.iter().map(|r| r[x]).collect()
     }
 
-    /// Retrieve column of matrix as a vector
+    /// Dimensions of the matrix as a `(height, width)` pair
+    pub(crate) fn dim(&self) -> (usize, usize) {
+        self.dim
+    }
+
+    /// Transpose the matrix, turning every H×W matrix into a W×H one
+    ///
+    /// <https://en.wikipedia.org/wiki/Transpose>
     ///
     /// # Examples
     ///
@@ -248,13 +259,19 @@ impl Matrix {
     ///                                           vec![3.0, 6.0, 9.0]]);
     /// assert_eq!(matrix.transpose(), expected);
     /// ```
+    ///
+    /// Non-square matrices are transposed as well:
+    /// ```
+    /// let matrix = lingebra::Matrix::new(vec![vec![1.0, 2.0, 3.0],
+    ///                                         vec![4.0, 5.0, 6.0]]);
+    /// let expected = lingebra::Matrix::new(vec![vec![1.0, 4.0],
+    ///                                           vec![2.0, 5.0],
+    ///                                           vec![3.0, 6.0]]);
+    /// assert_eq!(matrix.transpose(), expected);
+    /// ```
     pub fn transpose(self) -> Matrix {
-        assert_eq!(
-            self.dim.0, self.dim.1,
-            "Transposition works only for sqare matrices!"
-        );
         let mut res_vector = Vec::new();
-        for i in 0..self.dim.0 {
+        for i in 0..self.dim.1 {
             res_vector.push(self.col(i).to_vec());
         }
         Matrix::new(res_vector)
@@ -325,6 +342,41 @@ impl<'a> Add<&'a Matrix> for &'a Matrix {
     }
 }
 
+/// Addition permutations for owned and borrowed matrices
+///
+/// # Examples
+///
+/// ```
+/// let matrix = lingebra::Matrix::ones(2, 2);
+/// let expected = lingebra::Matrix::new(vec![vec![2.0, 2.0], vec![2.0, 2.0]]);
+/// assert_eq!(matrix.clone() + matrix.clone(), expected);
+/// assert_eq!(matrix.clone() + &matrix, expected);
+/// assert_eq!(&matrix + matrix.clone(), expected);
+/// ```
+impl Add<Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn add(self, other: Matrix) -> Matrix {
+        &self + &other
+    }
+}
+
+impl<'a> Add<&'a Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn add(self, other: &Matrix) -> Matrix {
+        &self + other
+    }
+}
+
+impl<'a> Add<Matrix> for &'a Matrix {
+    type Output = Matrix;
+
+    fn add(self, other: Matrix) -> Matrix {
+        self + &other
+    }
+}
+
 /// Subtraction for matrices
 ///
 /// # Examples
@@ -351,6 +403,42 @@ impl<'a> Sub<&'a Matrix> for &'a Matrix {
     }
 }
 
+/// Subtraction permutations for owned and borrowed matrices
+///
+/// # Examples
+///
+/// ```
+/// let matrix_a = lingebra::Matrix::ones(2, 2);
+/// let matrix_b = lingebra::Matrix::identity(2);
+/// let expected = lingebra::Matrix::new(vec![vec![0.0, 1.0], vec![1.0, 0.0]]);
+/// assert_eq!(matrix_a.clone() - matrix_b.clone(), expected);
+/// assert_eq!(matrix_a.clone() - &matrix_b, expected);
+/// assert_eq!(&matrix_a - matrix_b.clone(), expected);
+/// ```
+impl Sub<Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn sub(self, other: Matrix) -> Matrix {
+        &self - &other
+    }
+}
+
+impl<'a> Sub<&'a Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn sub(self, other: &Matrix) -> Matrix {
+        &self - other
+    }
+}
+
+impl<'a> Sub<Matrix> for &'a Matrix {
+    type Output = Matrix;
+
+    fn sub(self, other: Matrix) -> Matrix {
+        self - &other
+    }
+}
+
 /// Multiplication for matrices and scalars
 ///
 /// # Examples
@@ -375,6 +463,24 @@ impl<'a> Mul<f64> for &'a Matrix {
     }
 }
 
+/// Scalar multiplication for an owned matrix
+///
+/// # Examples
+///
+/// ```
+/// let matrix = lingebra::Matrix::identity(2);
+/// let expected = lingebra::Matrix::new(vec![vec![42.0, 0.0], vec![0.0, 42.0]]);
+/// let result = matrix * 42.0;
+/// assert_eq!(result, expected);
+/// ```
+impl Mul<f64> for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: f64) -> Matrix {
+        &self * rhs
+    }
+}
+
 /// Multiplication for matrices and vectors
 ///
 /// # Examples
@@ -403,6 +509,45 @@ impl<'a> Mul<&'a Vec<f64>> for &'a Matrix {
     }
 }
 
+/// Multiplication for two matrices (standard matrix product)
+///
+/// <https://en.wikipedia.org/wiki/Matrix_multiplication>
+///
+/// # Examples
+///
+/// ```
+/// let matrix_a = lingebra::Matrix::new(vec![vec![1.0, 2.0, 3.0],
+///                                           vec![4.0, 5.0, 6.0]]);
+/// let matrix_b = lingebra::Matrix::new(vec![vec![7.0, 8.0],
+///                                           vec![9.0, 10.0],
+///                                           vec![11.0, 12.0]]);
+/// let expected = lingebra::Matrix::new(vec![vec![58.0, 64.0],
+///                                           vec![139.0, 154.0]]);
+/// let result = &matrix_a * &matrix_b;
+/// assert_eq!(result, expected);
+/// ```
+impl<'a> Mul<&'a Matrix> for &'a Matrix {
+    type Output = Matrix;
+
+    fn mul(self, other: Self) -> Matrix {
+        assert_eq!(
+            self.dim.1, other.dim.0,
+            "Size of the matrices does not match for multiplication!"
+        );
+        let mut result = vec![vec![0.0; other.dim.1]; self.dim.0];
+        for i in 0..self.dim.0 {
+            for j in 0..other.dim.1 {
+                let mut sum = 0.0;
+                for k in 0..self.dim.1 {
+                    sum += self.matrix[i][k] * other.matrix[k][j];
+                }
+                result[i][j] = sum;
+            }
+        }
+        Matrix::new(result)
+    }
+}
+
 /// Division for matrices and scalars
 ///
 /// # Examples
@@ -426,3 +571,107 @@ impl<'a> Div<f64> for &'a Matrix {
         Matrix::new(result)
     }
 }
+
+/// Scalar division for an owned matrix
+///
+/// # Examples
+///
+/// ```
+/// let matrix = lingebra::Matrix::identity(2);
+/// let expected = lingebra::Matrix::new(vec![vec![0.2, 0.0], vec![0.0, 0.2]]);
+/// let result = matrix / 5.0;
+/// assert_eq!(result, expected);
+/// ```
+impl Div<f64> for Matrix {
+    type Output = Matrix;
+
+    fn div(self, rhs: f64) -> Matrix {
+        &self / rhs
+    }
+}
+
+/// In-place addition of another matrix
+///
+/// # Examples
+///
+/// ```
+/// let mut matrix = lingebra::Matrix::ones(2, 2);
+/// matrix += &lingebra::Matrix::identity(2);
+/// let expected = lingebra::Matrix::new(vec![vec![2.0, 1.0], vec![1.0, 2.0]]);
+/// assert_eq!(matrix, expected);
+/// ```
+impl AddAssign<&Matrix> for Matrix {
+    fn add_assign(&mut self, other: &Matrix) {
+        assert_eq!(&self.dim, &other.dim);
+        let matrix = Rc::make_mut(&mut self.matrix);
+        for x in 0..self.dim.0 {
+            for y in 0..self.dim.1 {
+                matrix[x][y] += other.matrix[x][y];
+            }
+        }
+    }
+}
+
+/// In-place subtraction of another matrix
+///
+/// # Examples
+///
+/// ```
+/// let mut matrix = lingebra::Matrix::ones(2, 2);
+/// matrix -= &lingebra::Matrix::identity(2);
+/// let expected = lingebra::Matrix::new(vec![vec![0.0, 1.0], vec![1.0, 0.0]]);
+/// assert_eq!(matrix, expected);
+/// ```
+impl SubAssign<&Matrix> for Matrix {
+    fn sub_assign(&mut self, other: &Matrix) {
+        assert_eq!(&self.dim, &other.dim);
+        let matrix = Rc::make_mut(&mut self.matrix);
+        for x in 0..self.dim.0 {
+            for y in 0..self.dim.1 {
+                matrix[x][y] -= other.matrix[x][y];
+            }
+        }
+    }
+}
+
+/// In-place scalar multiplication
+///
+/// # Examples
+///
+/// ```
+/// let mut matrix = lingebra::Matrix::identity(2);
+/// matrix *= 42.0;
+/// let expected = lingebra::Matrix::new(vec![vec![42.0, 0.0], vec![0.0, 42.0]]);
+/// assert_eq!(matrix, expected);
+/// ```
+impl MulAssign<f64> for Matrix {
+    fn mul_assign(&mut self, rhs: f64) {
+        let matrix = Rc::make_mut(&mut self.matrix);
+        for line in matrix.iter_mut() {
+            for value in line.iter_mut() {
+                *value *= rhs;
+            }
+        }
+    }
+}
+
+/// In-place scalar division
+///
+/// # Examples
+///
+/// ```
+/// let mut matrix = lingebra::Matrix::identity(2);
+/// matrix /= 5.0;
+/// let expected = lingebra::Matrix::new(vec![vec![0.2, 0.0], vec![0.0, 0.2]]);
+/// assert_eq!(matrix, expected);
+/// ```
+impl DivAssign<f64> for Matrix {
+    fn div_assign(&mut self, rhs: f64) {
+        let matrix = Rc::make_mut(&mut self.matrix);
+        for line in matrix.iter_mut() {
+            for value in line.iter_mut() {
+                *value /= rhs;
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::Matrix;
+use std::ops::Add;
+use std::ops::Mul;
+
+/// Sparse matrix stored in Compressed Sparse Row (CSR) form
+///
+/// <https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format)>
+///
+/// Only non-zero entries are kept. Within each row the entries are stored
+/// sorted by column index, which lets multiplication and addition run in time
+/// linear in the number of stored values.
+#[derive(Debug, Clone)]
+pub struct SparseMatrix {
+    values: Vec<f64>,
+    col_indices: Vec<usize>,
+    row_ptr: Vec<usize>,
+    dim: (usize, usize),
+}
+
+impl SparseMatrix {
+    /// Build a sparse matrix from a dense one, dropping every zero entry
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dense = lingebra::Matrix::new(vec![vec![0.0, 5.0],
+    ///                                        vec![0.0, 0.0]]);
+    /// let sparse = lingebra::SparseMatrix::from_dense(&dense);
+    /// assert_eq!(sparse.get(0, 1), 5.0);
+    /// assert_eq!(sparse.get(1, 0), 0.0);
+    /// ```
+    pub fn from_dense(matrix: &Matrix) -> SparseMatrix {
+        let (height, width) = matrix.dim();
+        let mut values = Vec::new();
+        let mut col_indices = Vec::new();
+        let mut row_ptr = Vec::with_capacity(height + 1);
+        row_ptr.push(0);
+        for i in 0..height {
+            for j in 0..width {
+                let value = matrix[i][j];
+                if value != 0.0 {
+                    values.push(value);
+                    col_indices.push(j);
+                }
+            }
+            row_ptr.push(values.len());
+        }
+        SparseMatrix {
+            values,
+            col_indices,
+            row_ptr,
+            dim: (height, width),
+        }
+    }
+
+    /// Expand back into a dense [`Matrix`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dense = lingebra::Matrix::new(vec![vec![0.0, 5.0],
+    ///                                        vec![3.0, 0.0]]);
+    /// let sparse = lingebra::SparseMatrix::from_dense(&dense);
+    /// assert_eq!(sparse.to_dense(), dense);
+    /// ```
+    pub fn to_dense(&self) -> Matrix {
+        let (height, width) = self.dim;
+        let mut result = vec![vec![0.0; width]; height];
+        for i in 0..height {
+            for k in self.row_ptr[i]..self.row_ptr[i + 1] {
+                result[i][self.col_indices[k]] = self.values[k];
+            }
+        }
+        Matrix::new(result)
+    }
+
+    /// Retrieve a single entry, returning `0.0` for a non-stored position
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dense = lingebra::Matrix::new(vec![vec![0.0, 5.0],
+    ///                                        vec![3.0, 0.0]]);
+    /// let sparse = lingebra::SparseMatrix::from_dense(&dense);
+    /// assert_eq!(sparse.get(1, 0), 3.0);
+    /// assert_eq!(sparse.get(0, 0), 0.0);
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> f64 {
+        for k in self.row_ptr[row]..self.row_ptr[row + 1] {
+            if self.col_indices[k] == col {
+                return self.values[k];
+            }
+        }
+        0.0
+    }
+}
+
+/// Multiplication of a sparse matrix by a dense vector
+///
+/// # Examples
+///
+/// ```
+/// let dense = lingebra::Matrix::new(vec![vec![1.0, 0.0, 2.0],
+///                                        vec![0.0, 3.0, 0.0]]);
+/// let sparse = lingebra::SparseMatrix::from_dense(&dense);
+/// let result = &sparse * &vec![1.0, 2.0, 3.0];
+/// assert_eq!(result, vec![7.0, 6.0]);
+/// ```
+impl<'a> Mul<&'a Vec<f64>> for &'a SparseMatrix {
+    type Output = Vec<f64>;
+
+    fn mul(self, rhs: &Vec<f64>) -> Vec<f64> {
+        assert_eq!(
+            self.dim.1,
+            rhs.len(),
+            "Size of matrix does not match with length of the vector!"
+        );
+        let mut result = vec![0.0; self.dim.0];
+        for i in 0..self.dim.0 {
+            let mut sum = 0.0;
+            for k in self.row_ptr[i]..self.row_ptr[i + 1] {
+                sum += self.values[k] * rhs[self.col_indices[k]];
+            }
+            result[i] = sum;
+        }
+        result
+    }
+}
+
+/// Addition of two sparse matrices by merging their sorted column runs
+///
+/// # Examples
+///
+/// ```
+/// let a = lingebra::SparseMatrix::from_dense(&lingebra::Matrix::new(vec![vec![1.0, 0.0],
+///                                                                        vec![0.0, 2.0]]));
+/// let b = lingebra::SparseMatrix::from_dense(&lingebra::Matrix::new(vec![vec![0.0, 4.0],
+///                                                                        vec![3.0, 0.0]]));
+/// let expected = lingebra::Matrix::new(vec![vec![1.0, 4.0], vec![3.0, 2.0]]);
+/// assert_eq!((&a + &b).to_dense(), expected);
+/// ```
+impl<'a> Add<&'a SparseMatrix> for &'a SparseMatrix {
+    type Output = SparseMatrix;
+
+    fn add(self, other: Self) -> SparseMatrix {
+        assert_eq!(self.dim, other.dim);
+        let (height, width) = self.dim;
+        let mut values = Vec::new();
+        let mut col_indices = Vec::new();
+        let mut row_ptr = Vec::with_capacity(height + 1);
+        row_ptr.push(0);
+
+        for i in 0..height {
+            let (mut a, a_end) = (self.row_ptr[i], self.row_ptr[i + 1]);
+            let (mut b, b_end) = (other.row_ptr[i], other.row_ptr[i + 1]);
+            while a < a_end || b < b_end {
+                let col_a = if a < a_end { self.col_indices[a] } else { width };
+                let col_b = if b < b_end { other.col_indices[b] } else { width };
+                let (col, value) = if col_a < col_b {
+                    let entry = (col_a, self.values[a]);
+                    a += 1;
+                    entry
+                } else if col_b < col_a {
+                    let entry = (col_b, other.values[b]);
+                    b += 1;
+                    entry
+                } else {
+                    let entry = (col_a, self.values[a] + other.values[b]);
+                    a += 1;
+                    b += 1;
+                    entry
+                };
+                if value != 0.0 {
+                    values.push(value);
+                    col_indices.push(col);
+                }
+            }
+            row_ptr.push(values.len());
+        }
+
+        SparseMatrix {
+            values,
+            col_indices,
+            row_ptr,
+            dim: (height, width),
+        }
+    }
+}